@@ -1,5 +1,10 @@
 use clap::Parser;
+use owo_colors::OwoColorize;
 use std::path::PathBuf;
+use toolbox_core::registry::Registry;
+use toolbox_core::resolve::{self, Resolution};
+use toolbox_core::runner::{CommandRunner, ProcessRunner, Stream};
+use toolbox_core::{config, format, integrity, registry};
 
 /// Thin command-line interface for the toolbox scaffold.
 #[derive(Debug, Parser)]
@@ -8,25 +13,274 @@ struct Cli {
     /// Path to a toolbox config file.
     #[arg(long)]
     config: Option<PathBuf>,
+    /// Named `[profiles.<name>]` table to layer on top of the base config. Falls back to
+    /// the `WPSI_UTILS_PROFILE` environment variable when unset.
+    #[arg(long)]
+    profile: Option<String>,
+    /// Directory to discover modules under.
+    #[arg(long, default_value = "modules")]
+    modules_dir: PathBuf,
     /// List available modules defined under /modules.
     #[arg(long)]
     list_modules: bool,
     /// Run a specific module by identifier.
     #[arg(long, value_name = "MODULE")]
     run: Option<String>,
+    /// Action to run within the module selected by `--run`.
+    #[arg(long, value_name = "ACTION")]
+    action: Option<String>,
+    /// Extra `key=value` pairs made available to the action's command template as `{key}`.
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = parse_key_val)]
+    set: Vec<(String, String)>,
+    /// Walk all discovered modules and print a checksum manifest instead of running anything.
+    #[arg(long)]
+    verify: bool,
+    /// Sync the module catalog from a git repository. Reuses the existing `origin` remote
+    /// when no URL is given and `modules_dir` was already synced from git.
+    #[arg(long, value_name = "URL", num_args = 0..=1, default_missing_value = "")]
+    update: Option<String>,
 }
 
 fn main() {
     let cli = Cli::parse();
 
-    println!("Not implemented – add your scripts in /modules.");
-    if let Some(path) = cli.config {
-        println!("Requested config: {}", path.display());
+    if cli.verify {
+        run_verify(&cli.modules_dir);
+        return;
+    }
+
+    if let Some(url) = cli.update {
+        run_update(&cli.modules_dir, url);
+        return;
+    }
+
+    if let Some(path) = &cli.config {
+        run_config(path, cli.profile.as_deref(), &cli.modules_dir);
     }
     if cli.list_modules {
-        println!("TODO: enumerate modules by reading module metadata.");
+        run_list_modules(&cli.modules_dir);
     }
     if let Some(module) = cli.run {
-        println!("TODO: invoke module `{module}` with your own automation.");
+        let allow_unverified = resolve_allow_unverified(cli.config.as_deref(), cli.profile.as_deref());
+        run_action(&cli.modules_dir, &module, cli.action.as_deref(), &cli.set, allow_unverified);
+    }
+}
+
+/// Load `Config.allow_unverified` from `--config` (layering `--profile`), defaulting to
+/// `false` when no config file was given or it failed to load.
+fn resolve_allow_unverified(path: Option<&std::path::Path>, profile: Option<&str>) -> bool {
+    let Some(path) = path else { return false };
+    config::load_config_profile(path, profile, &[]).map(|config| config.allow_unverified).unwrap_or(false)
+}
+
+/// Load `--config`, layering the selected profile and env overrides, and print the result.
+fn run_config(path: &PathBuf, profile: Option<&str>, modules_dir: &PathBuf) {
+    let known_modules: Vec<String> = registry::discover_modules(modules_dir)
+        .map(|modules| modules.into_iter().map(|module| module.id).collect())
+        .unwrap_or_default();
+
+    match config::load_config_profile(path, profile, &known_modules) {
+        Ok(loaded) => println!("Loaded config from {}: {loaded:?}", path.display()),
+        Err(err) => {
+            eprintln!("Failed to load config from {}: {err}", path.display());
+            std::process::exit(1);
+        }
+    }
+}
+
+/// List every module discovered under `modules_dir`, one line each with its category,
+/// description, and declared actions.
+fn run_list_modules(modules_dir: &PathBuf) {
+    let modules = match registry::discover_modules(modules_dir) {
+        Ok(modules) => modules,
+        Err(err) => {
+            eprintln!("Failed to discover modules in {}: {err}", modules_dir.display());
+            std::process::exit(1);
+        }
+    };
+
+    if modules.is_empty() {
+        println!("No modules found under {}.", modules_dir.display());
+        return;
+    }
+
+    for module in &modules {
+        let actions: Vec<_> = module.actions.keys().cloned().collect();
+        println!("{} [{}] - {} (actions: {})", module.id, module.category, module.description, actions.join(", "));
+    }
+}
+
+/// Format a " Did you mean `x`?" hint for an unknown name, if one known name is close enough.
+fn suggestion_hint(name: &str, known: &[String]) -> String {
+    match resolve::resolve(name, known) {
+        Resolution::Suggestion(candidate) => format!(" Did you mean `{candidate}`?"),
+        Resolution::Exact(_) | Resolution::None => String::new(),
+    }
+}
+
+/// Parse a `key=value` CLI argument for `--set`.
+fn parse_key_val(raw: &str) -> std::result::Result<(String, String), String> {
+    raw.split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("invalid KEY=VALUE pair (no `=` found): `{raw}`"))
+}
+
+/// Expand and run a single module action, templating its command against
+/// built-in and user-supplied (`--set`) variables.
+fn run_action(
+    modules_dir: &PathBuf,
+    module_id: &str,
+    action: Option<&str>,
+    overrides: &[(String, String)],
+    allow_unverified: bool,
+) {
+    let modules = match registry::discover_modules(modules_dir) {
+        Ok(modules) => modules,
+        Err(err) => {
+            eprintln!("Failed to discover modules in {}: {err}", modules_dir.display());
+            std::process::exit(1);
+        }
+    };
+
+    let Some(module) = modules.iter().find(|m| m.id == module_id) else {
+        let known: Vec<String> = modules.iter().map(|m| m.id.clone()).collect();
+        eprintln!("Unknown module `{module_id}`.{}", suggestion_hint(module_id, &known));
+        std::process::exit(1);
+    };
+
+    let Some(action_name) = action else {
+        let available: Vec<_> = module.actions.keys().cloned().collect();
+        eprintln!("Specify --action for module `{module_id}`. Available: {}", available.join(", "));
+        std::process::exit(1);
+    };
+
+    let Some(template) = module.actions.get(action_name) else {
+        let known: Vec<String> = module.actions.keys().cloned().collect();
+        eprintln!("Module `{module_id}` has no action `{action_name}`.{}", suggestion_hint(action_name, &known));
+        std::process::exit(1);
+    };
+
+    let mut context =
+        format::builtin_context(&module.root.display().to_string(), &module.id, &module.category);
+    for (key, value) in overrides {
+        context.insert(key.clone(), value.clone());
+    }
+
+    let command = match format::expand(template, &context) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("Failed to expand action `{action_name}`: {err}");
+            std::process::exit(1);
+        }
+    };
+
+    let runner = ProcessRunner::new();
+    let cancel = std::sync::atomic::AtomicBool::new(false);
+    let env = std::collections::HashMap::new();
+    let result = runner.run(
+        module,
+        action_name,
+        &command,
+        &env,
+        allow_unverified,
+        &mut |line| match line.stream {
+            Stream::Stdout if line.text.starts_with('✓') => println!("{}", line.text.green()),
+            Stream::Stdout => println!("{}", line.text),
+            Stream::Stderr if line.text.starts_with('✗') => eprintln!("{}", line.text.red()),
+            Stream::Stderr => eprintln!("{}", line.text),
+        },
+        &cancel,
+    );
+
+    match result {
+        Ok(outcome) => println!("`{action_name}` exited {} in {:.2?}", outcome.exit_code, outcome.duration),
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print a path -> digest -> ok/mismatch manifest for every checksum pinned across the catalog.
+fn run_verify(modules_dir: &PathBuf) {
+    let modules = match registry::discover_modules(modules_dir) {
+        Ok(modules) => modules,
+        Err(err) => {
+            eprintln!("Failed to discover modules in {}: {err}", modules_dir.display());
+            return;
+        }
+    };
+
+    let mut mismatches = 0usize;
+
+    for module in &modules {
+        for (action, expected) in &module.checksums {
+            let command = module.actions.get(action).or_else(|| {
+                let (pipeline, index) = action.split_once('#')?;
+                let index: usize = index.parse().ok()?;
+                module.pipelines.get(pipeline)?.steps.get(index).map(|step| &step.command)
+            });
+            let script = command.and_then(|cmd| cmd.split_whitespace().next());
+            let Some(script) = script else {
+                println!("{}/{action}: no action command to verify against", module.id);
+                continue;
+            };
+
+            let script_path = module.root.join(script);
+            match integrity::hash_file(&script_path) {
+                Ok(actual) if actual.eq_ignore_ascii_case(expected) => {
+                    println!("{} -> {expected} -> ok", script_path.display());
+                }
+                Ok(actual) => {
+                    mismatches += 1;
+                    println!("{} -> {expected} -> mismatch (got {actual})", script_path.display());
+                }
+                Err(err) => {
+                    mismatches += 1;
+                    println!("{} -> {expected} -> error ({err})", script_path.display());
+                }
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Clone or fast-forward the module catalog from git, falling back to the
+/// existing `origin` remote when `url` is empty.
+fn run_update(modules_dir: &PathBuf, url: String) {
+    let registry = Registry::new(modules_dir.clone());
+
+    let url = if url.is_empty() {
+        match registry.origin_url() {
+            Ok(Some(url)) => url,
+            Ok(None) => {
+                eprintln!("No URL given and {} isn't a git checkout yet.", modules_dir.display());
+                std::process::exit(1);
+            }
+            Err(err) => {
+                eprintln!("Failed to read existing origin remote: {err}");
+                std::process::exit(1);
+            }
+        }
+    } else {
+        url
+    };
+
+    let result = registry.sync_from_git(&url, None, |progress| {
+        println!(
+            "Fetching... {}/{} objects ({} bytes)",
+            progress.received_objects, progress.total_objects, progress.received_bytes
+        );
+    });
+
+    match result {
+        Ok(modules) => println!("Synced {} from {url}: {} modules discovered.", modules_dir.display(), modules.len()),
+        Err(err) => {
+            eprintln!("Failed to sync modules from {url}: {err}");
+            std::process::exit(1);
+        }
     }
 }
@@ -1,6 +1,8 @@
 //! Configuration models and loaders for the toolbox scaffold.
 
 use crate::{Error, Result};
+use std::collections::HashMap;
+use std::env;
 use std::path::Path;
 
 /// High-level configuration for the toolbox runtime.
@@ -12,29 +14,227 @@ pub struct Config {
     pub skip_confirmation: bool,
     /// Whether to bypass size checks.
     pub size_bypass: bool,
+    /// Whether to run actions whose pinned checksum doesn't match, instead of refusing.
+    pub allow_unverified: bool,
+}
+
+/// The base config table plus any named `[profiles.<name>]` overrides.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct RawConfig {
+    #[serde(default)]
+    auto_execute: Vec<String>,
+    #[serde(default)]
+    skip_confirmation: bool,
+    #[serde(default)]
+    size_bypass: bool,
+    #[serde(default)]
+    allow_unverified: bool,
+    #[serde(default)]
+    profiles: HashMap<String, ProfileOverrides>,
+}
+
+/// A named profile table; unset fields leave the base config untouched.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct ProfileOverrides {
+    auto_execute: Option<Vec<String>>,
+    skip_confirmation: Option<bool>,
+    size_bypass: Option<bool>,
+    allow_unverified: Option<bool>,
 }
 
 /// Attempt to load configuration from the provided path.
 ///
-/// Expected TOML keys:
-/// - `auto_execute` as an array of module identifiers
-/// - `skip_confirmation` as a boolean
-/// - `size_bypass` as a boolean
+/// Equivalent to [`load_config_profile`] with no explicit profile and no
+/// `auto_execute` validation against a discovered module set.
 pub fn load_config(path: impl AsRef<Path>) -> Result<Config> {
-    let _ = path.as_ref();
-    // TODO: parse TOML once the configuration format is finalized.
-    Err(Error::Unimplemented)
+    load_config_profile(path, None, &[])
+}
+
+/// Load configuration, applying a named profile and environment overrides on top of the
+/// base table, with precedence `base < profile < WPSI_UTILS_* env vars`.
+///
+/// `profile` selects a `[profiles.<name>]` table (typically from a `--profile` CLI flag);
+/// when `None`, the `WPSI_UTILS_PROFILE` environment variable is used instead, if set.
+///
+/// When `known_modules` is non-empty, every `auto_execute` entry must name one of them, or
+/// `Error::UnknownModule` is returned.
+pub fn load_config_profile(
+    path: impl AsRef<Path>,
+    profile: Option<&str>,
+    known_modules: &[String],
+) -> Result<Config> {
+    let raw: RawConfig = toml::from_str(&std::fs::read_to_string(path.as_ref())?)?;
+
+    let mut config = Config {
+        auto_execute: raw.auto_execute,
+        skip_confirmation: raw.skip_confirmation,
+        size_bypass: raw.size_bypass,
+        allow_unverified: raw.allow_unverified,
+    };
+
+    let profile_name = profile.map(str::to_string).or_else(|| env::var("WPSI_UTILS_PROFILE").ok());
+    if let Some(profile) = profile_name.and_then(|name| raw.profiles.get(&name).cloned()) {
+        apply_profile(&mut config, &profile);
+    }
+
+    apply_env_overrides(&mut config);
+
+    if !known_modules.is_empty() {
+        for id in &config.auto_execute {
+            if !known_modules.iter().any(|known| known == id) {
+                return Err(Error::UnknownModule(id.clone()));
+            }
+        }
+    }
+
+    Ok(config)
+}
+
+fn apply_profile(config: &mut Config, profile: &ProfileOverrides) {
+    if let Some(auto_execute) = &profile.auto_execute {
+        config.auto_execute = auto_execute.clone();
+    }
+    if let Some(skip_confirmation) = profile.skip_confirmation {
+        config.skip_confirmation = skip_confirmation;
+    }
+    if let Some(size_bypass) = profile.size_bypass {
+        config.size_bypass = size_bypass;
+    }
+    if let Some(allow_unverified) = profile.allow_unverified {
+        config.allow_unverified = allow_unverified;
+    }
+}
+
+fn apply_env_overrides(config: &mut Config) {
+    if let Ok(value) = env::var("WPSI_UTILS_SKIP_CONFIRMATION") {
+        if let Ok(parsed) = value.parse() {
+            config.skip_confirmation = parsed;
+        }
+    }
+    if let Ok(value) = env::var("WPSI_UTILS_SIZE_BYPASS") {
+        if let Ok(parsed) = value.parse() {
+            config.size_bypass = parsed;
+        }
+    }
+    if let Ok(value) = env::var("WPSI_UTILS_ALLOW_UNVERIFIED") {
+        if let Ok(parsed) = value.parse() {
+            config.allow_unverified = parsed;
+        }
+    }
+    if let Ok(value) = env::var("WPSI_UTILS_AUTO_EXECUTE") {
+        config.auto_execute = value.split(',').map(str::to_string).filter(|id| !id.is_empty()).collect();
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Mutex;
+
+    // Environment variables are process-global, so serialize tests that touch WPSI_UTILS_*.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn write_config(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("wpsi_utils-test-{name}-{}.toml", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_base_config_with_no_profile() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("WPSI_UTILS_PROFILE");
+        let path = write_config(
+            "base",
+            r#"
+            auto_execute = ["alpha"]
+            skip_confirmation = false
+            size_bypass = false
+            "#,
+        );
+
+        let config = load_config(&path).unwrap();
+        assert_eq!(config.auto_execute, vec!["alpha".to_string()]);
+        assert!(!config.skip_confirmation);
+
+        std::fs::remove_file(path).unwrap();
+    }
 
     #[test]
-    #[ignore = "placeholder"]
-    fn loads_example_config() {
-        let path = Path::new("../config/example_config.toml");
-        let cfg = load_config(path).expect("TODO: replace with real parsing");
-        assert!(cfg.auto_execute.is_empty());
+    fn profile_overrides_base_table() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("WPSI_UTILS_PROFILE");
+        let path = write_config(
+            "profile-overrides",
+            r#"
+            auto_execute = ["alpha"]
+            skip_confirmation = false
+
+            [profiles.ci]
+            skip_confirmation = true
+            "#,
+        );
+
+        let config = load_config_profile(&path, Some("ci"), &[]).unwrap();
+        assert!(config.skip_confirmation);
+        assert_eq!(config.auto_execute, vec!["alpha".to_string()]);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn profile_env_var_selects_profile_when_arg_is_none() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let path = write_config(
+            "profile-env-select",
+            r#"
+            skip_confirmation = false
+
+            [profiles.ci]
+            skip_confirmation = true
+            "#,
+        );
+
+        env::set_var("WPSI_UTILS_PROFILE", "ci");
+        let config = load_config_profile(&path, None, &[]).unwrap();
+        env::remove_var("WPSI_UTILS_PROFILE");
+
+        assert!(config.skip_confirmation);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn env_override_wins_over_profile() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("WPSI_UTILS_PROFILE");
+        let path = write_config(
+            "env-wins",
+            r#"
+            skip_confirmation = false
+
+            [profiles.ci]
+            skip_confirmation = true
+            "#,
+        );
+
+        env::set_var("WPSI_UTILS_SKIP_CONFIRMATION", "false");
+        let config = load_config_profile(&path, Some("ci"), &[]).unwrap();
+        env::remove_var("WPSI_UTILS_SKIP_CONFIRMATION");
+
+        assert!(!config.skip_confirmation);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn unknown_auto_execute_module_is_rejected() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        env::remove_var("WPSI_UTILS_PROFILE");
+        let path = write_config("unknown-module", r#"auto_execute = ["missing"]"#);
+
+        let known_modules = vec!["alpha".to_string()];
+        let result = load_config_profile(&path, None, &known_modules);
+        assert!(matches!(result, Err(Error::UnknownModule(id)) if id == "missing"));
+
+        std::fs::remove_file(path).unwrap();
     }
 }
@@ -0,0 +1,37 @@
+//! Span-annotated diagnostics for malformed or invalid `module.toml` files.
+//!
+//! Parse and validation failures are reported with the offending file, the
+//! byte span (when known), and a human message, instead of collapsing into a
+//! raw `toml::de::Error` debug print.
+
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use codespan_reporting::files::SimpleFile;
+use codespan_reporting::term::{
+    self,
+    termcolor::{ColorChoice, StandardStream},
+};
+
+/// Emit a diagnostic for a TOML parse error, pointing at its byte span when one is available.
+pub fn report_parse_error(path: &str, source: &str, error: &toml::de::Error) {
+    let mut diagnostic = Diagnostic::error().with_message(error.message().to_string());
+
+    if let Some(span) = error.span() {
+        diagnostic = diagnostic.with_labels(vec![Label::primary((), span)]);
+    }
+
+    emit(path, source, &diagnostic);
+}
+
+/// Emit a diagnostic for a semantic validation failure (e.g. empty `id`, duplicate module
+/// ids, or an action referencing a script that doesn't exist) that has no byte span of its own.
+pub fn report_validation_error(path: &str, source: &str, message: impl Into<String>) {
+    let diagnostic = Diagnostic::error().with_message(message.into());
+    emit(path, source, &diagnostic);
+}
+
+fn emit(path: &str, source: &str, diagnostic: &Diagnostic<()>) {
+    let file = SimpleFile::new(path, source);
+    let writer = StandardStream::stderr(ColorChoice::Auto);
+    let config = term::Config::default();
+    let _ = term::emit(&mut writer.lock(), &config, &file, diagnostic);
+}
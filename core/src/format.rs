@@ -0,0 +1,106 @@
+//! Command templating for module actions.
+//!
+//! Action commands in `module.toml` can reference `{variable}` placeholders
+//! instead of hardcoding machine-specific values. A placeholder is resolved,
+//! in order, against:
+//! - an `{env:NAME}` lookup of the environment variable `NAME`
+//! - the supplied context map (built-ins like `{module_root}` plus any
+//!   user-supplied `--set key=value` pairs)
+//! - a `{var:-default}` fallback, if one was given
+//!
+//! An unresolved placeholder is an error rather than being left verbatim, so
+//! a typo'd variable name fails loudly instead of silently reaching the shell.
+
+use crate::{Error, Result};
+use std::collections::HashMap;
+
+enum Segment {
+    Literal(String),
+    Variable { name: String, default: Option<String> },
+}
+
+/// Expand every `{variable}` placeholder in `template` against `context`.
+pub fn expand(template: &str, context: &HashMap<String, String>) -> Result<String> {
+    let mut out = String::with_capacity(template.len());
+
+    for segment in parse(template) {
+        match segment {
+            Segment::Literal(text) => out.push_str(&text),
+            Segment::Variable { name, default } => {
+                out.push_str(&resolve(&name, default.as_deref(), context)?);
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+fn parse(template: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if ch != '{' {
+            literal.push(ch);
+            continue;
+        }
+
+        let mut body = String::new();
+        let mut closed = false;
+        for next in chars.by_ref() {
+            if next == '}' {
+                closed = true;
+                break;
+            }
+            body.push(next);
+        }
+
+        if !closed {
+            literal.push('{');
+            literal.push_str(&body);
+            continue;
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(std::mem::take(&mut literal)));
+        }
+
+        let (name, default) = match body.split_once(":-") {
+            Some((name, default)) => (name.to_string(), Some(default.to_string())),
+            None => (body, None),
+        };
+        segments.push(Segment::Variable { name, default });
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    segments
+}
+
+fn resolve(name: &str, default: Option<&str>, context: &HashMap<String, String>) -> Result<String> {
+    if let Some(env_name) = name.strip_prefix("env:") {
+        if let Ok(value) = std::env::var(env_name) {
+            return Ok(value);
+        }
+    } else if let Some(value) = context.get(name) {
+        return Ok(value.clone());
+    }
+
+    if let Some(default) = default {
+        return Ok(default.to_string());
+    }
+
+    Err(Error::UnknownVariable(name.to_string()))
+}
+
+/// Built-in variables derived from a module, ready to merge with user-supplied values.
+pub fn builtin_context(module_root: &str, module_id: &str, category: &str) -> HashMap<String, String> {
+    HashMap::from([
+        ("module_root".to_string(), module_root.to_string()),
+        ("module_id".to_string(), module_id.to_string()),
+        ("category".to_string(), category.to_string()),
+    ])
+}
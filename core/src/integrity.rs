@@ -0,0 +1,38 @@
+//! Script integrity verification.
+//!
+//! Modules can pin an action's script to a known-good SHA-256 digest in
+//! `module.toml`. Before a `CommandRunner` spawns that action, the referenced
+//! script is re-hashed and compared against the recorded digest so a script
+//! that was tampered with (or silently updated upstream) doesn't run unnoticed.
+
+use crate::{Error, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// Compute the hex-encoded SHA-256 digest of the file at `path`.
+pub fn hash_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Verify that `path` hashes to `expected`.
+///
+/// Returns `Ok(())` when the digests match or when `allow_unverified` is set.
+/// Otherwise returns `Error::ChecksumMismatch`. A file that can't be read is
+/// an IO error either way, since it is hashed before `allow_unverified` is checked.
+pub fn verify_script(path: &Path, expected: &str, allow_unverified: bool) -> Result<()> {
+    let actual = hash_file(path)?;
+
+    if actual.eq_ignore_ascii_case(expected) || allow_unverified {
+        return Ok(());
+    }
+
+    Err(Error::ChecksumMismatch {
+        expected: expected.to_string(),
+        actual,
+        path: path.to_path_buf(),
+    })
+}
@@ -1,14 +1,19 @@
 //! Core crate exposing placeholder types for the toolbox workspace.
 
 pub mod config;
+pub mod diagnostics;
+pub mod format;
+pub mod integrity;
 pub mod registry;
+pub mod resolve;
 pub mod runner;
 
 pub use config::Config;
 pub use registry::{Module, Registry};
-pub use runner::{CommandRunner, NoopRunner};
+pub use runner::{CommandRunner, ProcessRunner};
 
 use std::io;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Common error type for the toolbox scaffolding.
@@ -23,6 +28,21 @@ pub enum Error {
     /// Indicates module parsing failed.
     #[error("TOML error: {0}")]
     Toml(#[from] toml::de::Error),
+    /// Raised when a spawned action command exits with a non-zero status.
+    #[error("command `{command}` failed with exit code {code}")]
+    CommandFailed { code: i32, command: String },
+    /// Raised when a script's SHA-256 digest doesn't match the one pinned in `module.toml`.
+    #[error("checksum mismatch for {path}: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String, path: PathBuf },
+    /// Wrapper for git2 errors raised while syncing a module repository.
+    #[error("git error: {0}")]
+    Git(#[from] git2::Error),
+    /// Raised when an action template references a placeholder with no value and no default.
+    #[error("unknown template variable `{0}`")]
+    UnknownVariable(String),
+    /// Raised when `auto_execute` names a module id that discovery didn't find.
+    #[error("unknown module `{0}` in auto_execute")]
+    UnknownModule(String),
 }
 
 /// Convenient alias for results returned by the core crate.
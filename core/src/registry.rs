@@ -1,6 +1,6 @@
 //! Module registry utilities.
 
-use crate::Result;
+use crate::{diagnostics, Result};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -18,6 +18,69 @@ pub struct Module {
     pub root: PathBuf,
     #[serde(default)]
     pub actions: HashMap<String, String>,
+    /// Optional SHA-256 digests (hex-encoded) pinning each action's script to a known-good hash.
+    ///
+    /// A pipeline's own steps are pinned individually under `"{pipeline}#{step index}"`
+    /// (e.g. `"deploy#0"`), not under the pipeline's own name — every step would otherwise
+    /// collide on a single digest.
+    #[serde(default)]
+    pub checksums: HashMap<String, String>,
+    /// Named parameters an action prompts for before it runs, exported to the spawned
+    /// process as environment variables.
+    #[serde(default)]
+    pub params: HashMap<String, Vec<ActionParam>>,
+    /// Composite actions that run an ordered sequence of steps, keyed by action name
+    /// alongside (and taking precedence over) a same-named entry in `actions`.
+    #[serde(default)]
+    pub pipelines: HashMap<String, Pipeline>,
+}
+
+/// A single named parameter an action accepts, collected interactively before execution.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub struct ActionParam {
+    /// Both the prompt label and the environment variable name exported to the process.
+    pub name: String,
+    /// Pre-filled value offered to the user; left blank if absent.
+    #[serde(default)]
+    pub default: Option<String>,
+    /// Whether the value should be masked in the UI and omitted from status messages.
+    #[serde(default)]
+    pub secret: bool,
+}
+
+/// A composite action: an ordered sequence of steps run as a single selectable action.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub struct Pipeline {
+    #[serde(default)]
+    pub steps: Vec<PipelineStep>,
+    /// Stop running the remaining steps as soon as one fails.
+    #[serde(default = "default_stop_on_failure")]
+    pub stop_on_failure: bool,
+}
+
+fn default_stop_on_failure() -> bool {
+    true
+}
+
+/// A single step of a [`Pipeline`]: a shell command gated by an optional [`StepCondition`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Deserialize, serde::Serialize)]
+pub struct PipelineStep {
+    pub command: String,
+    /// Skip this step unless the condition holds; unconditional if absent.
+    #[serde(default)]
+    pub when: Option<StepCondition>,
+}
+
+/// A predicate gating whether a [`PipelineStep`] runs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepCondition {
+    /// Only run if the step at this zero-based index succeeded.
+    StepSucceeded(usize),
+    /// Only run if the step at this zero-based index failed.
+    StepFailed(usize),
+    /// Only run if the named parameter's collected value equals this string.
+    ParamEquals { param: String, value: String },
 }
 
 /// Registry handle that knows where module metadata lives on disk.
@@ -32,10 +95,101 @@ impl Registry {
         Self { modules_dir }
     }
 
-    /// Enumerate modules using the placeholder discovery routine.
+    /// Enumerate modules using the sequential single-threaded walk.
     pub fn modules(&self) -> Result<Vec<Module>> {
         discover_modules(&self.modules_dir)
     }
+
+    /// Enumerate modules using a `walkdir` traversal with `rayon`-parallel parsing.
+    ///
+    /// Prefer this over [`Registry::modules`] for large catalogs (e.g. ones
+    /// synced via [`Registry::sync_from_git`]); small trees don't benefit
+    /// enough to be worth the thread pool overhead.
+    pub fn modules_parallel(&self) -> Result<Vec<Module>> {
+        discover_modules_parallel(&self.modules_dir)
+    }
+
+    /// Return the `origin` remote URL of an already-synced module catalog, if any.
+    pub fn origin_url(&self) -> Result<Option<String>> {
+        if !self.modules_dir.join(".git").is_dir() {
+            return Ok(None);
+        }
+        let repo = git2::Repository::open(&self.modules_dir)?;
+        Ok(repo.find_remote("origin")?.url().map(str::to_string))
+    }
+
+    /// Clone or fast-forward a remote git repository of modules into `modules_dir`.
+    ///
+    /// If `modules_dir/.git` already exists it is fetched and reset to `rev`
+    /// (defaulting to the remote's default branch); otherwise the repository
+    /// is cloned fresh. `on_progress` is invoked as transfer progress comes in
+    /// so callers (e.g. the TUI footer) can render fetch status.
+    pub fn sync_from_git(
+        &self,
+        url: &str,
+        rev: Option<&str>,
+        mut on_progress: impl FnMut(SyncProgress),
+    ) -> Result<Vec<Module>> {
+        let git_dir = self.modules_dir.join(".git");
+
+        let repo = if git_dir.is_dir() {
+            let repo = git2::Repository::open(&self.modules_dir)?;
+            {
+                let mut remote = repo.find_remote("origin")?;
+                let mut callbacks = git2::RemoteCallbacks::new();
+                callbacks.transfer_progress(|progress| {
+                    on_progress(SyncProgress::from(&progress));
+                    true
+                });
+                let mut fetch_options = git2::FetchOptions::new();
+                fetch_options.remote_callbacks(callbacks);
+                remote.fetch(&[rev.unwrap_or("HEAD")], Some(&mut fetch_options), None)?;
+            }
+
+            // `fetch` above only moves `FETCH_HEAD` to whatever `rev` (or `HEAD`)
+            // resolved to on the remote; it does not touch `origin/{rev}`, so reset
+            // against `FETCH_HEAD` rather than a remote-tracking ref that may be stale.
+            let target = repo.find_reference("FETCH_HEAD")?.peel(git2::ObjectType::Commit)?;
+            repo.reset(&target, git2::ResetType::Hard, None)?;
+            repo
+        } else {
+            fs::create_dir_all(&self.modules_dir)?;
+            let mut callbacks = git2::RemoteCallbacks::new();
+            callbacks.transfer_progress(|progress| {
+                on_progress(SyncProgress::from(&progress));
+                true
+            });
+            let mut fetch_options = git2::FetchOptions::new();
+            fetch_options.remote_callbacks(callbacks);
+            let mut builder = git2::build::RepoBuilder::new();
+            builder.fetch_options(fetch_options);
+            if let Some(rev) = rev {
+                builder.branch(rev);
+            }
+            builder.clone(url, &self.modules_dir)?
+        };
+
+        drop(repo);
+        discover_modules(&self.modules_dir)
+    }
+}
+
+/// Progress update emitted while syncing the module catalog from git.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SyncProgress {
+    pub received_objects: usize,
+    pub total_objects: usize,
+    pub received_bytes: usize,
+}
+
+impl From<&git2::Progress<'_>> for SyncProgress {
+    fn from(progress: &git2::Progress<'_>) -> Self {
+        Self {
+            received_objects: progress.received_objects(),
+            total_objects: progress.total_objects(),
+            received_bytes: progress.received_bytes(),
+        }
+    }
 }
 
 /// Look for modules beneath the path.
@@ -47,15 +201,14 @@ pub fn discover_modules(dir: impl AsRef<Path>) -> Result<Vec<Module>> {
 
     let mut modules = Vec::new();
     visit_dir(dir, &mut modules)?;
+    validate_unique_ids(&modules);
     Ok(modules)
 }
 
 fn visit_dir(dir: &Path, modules: &mut Vec<Module>) -> Result<()> {
     let module_file = dir.join("module.toml");
     if module_file.is_file() {
-        let mut module: Module = toml::from_str(&fs::read_to_string(&module_file)?)?;
-        module.root = dir.to_path_buf();
-        modules.push(module);
+        modules.push(load_module_file(dir, &module_file)?);
         return Ok(());
     }
 
@@ -69,3 +222,114 @@ fn visit_dir(dir: &Path, modules: &mut Vec<Module>) -> Result<()> {
 
     Ok(())
 }
+
+/// Parse and validate the `module.toml` at `module_file`, rooted at `dir`.
+fn load_module_file(dir: &Path, module_file: &Path) -> Result<Module> {
+    let display_path = module_file.display().to_string();
+    let source = fs::read_to_string(module_file)?;
+
+    let mut module: Module = match toml::from_str(&source) {
+        Ok(module) => module,
+        Err(err) => {
+            diagnostics::report_parse_error(&display_path, &source, &err);
+            return Err(err.into());
+        }
+    };
+    module.root = dir.to_path_buf();
+
+    if module.id.is_empty() {
+        diagnostics::report_validation_error(&display_path, &source, "missing required field `id`");
+    }
+
+    for (action, command) in &module.actions {
+        if let Some(script) = referenced_script(command) {
+            if !dir.join(script).exists() {
+                diagnostics::report_validation_error(
+                    &display_path,
+                    &source,
+                    format!("action `{action}` references nonexistent script `{script}`"),
+                );
+            }
+        }
+    }
+
+    Ok(module)
+}
+
+/// Look for modules beneath the path using a parallel `walkdir` traversal: directories are
+/// walked single-threaded to collect `module.toml` locations (pruning descent below any match,
+/// same leaf-module rule as [`discover_modules`]), then `rayon` parses them concurrently.
+pub fn discover_modules_parallel(dir: impl AsRef<Path>) -> Result<Vec<Module>> {
+    use rayon::prelude::*;
+
+    let dir = dir.as_ref();
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let module_dirs = find_module_dirs(dir);
+
+    let mut modules = module_dirs
+        .into_par_iter()
+        .map(|module_dir| {
+            let module_file = module_dir.join("module.toml");
+            load_module_file(&module_dir, &module_file)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    modules.sort_by(|a, b| (&a.category, &a.name).cmp(&(&b.category, &b.name)));
+    validate_unique_ids(&modules);
+    Ok(modules)
+}
+
+/// Collect every directory containing a `module.toml`, without descending below a match.
+fn find_module_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let mut walker = walkdir::WalkDir::new(dir).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        if entry.path().join("module.toml").is_file() {
+            dirs.push(entry.into_path());
+            walker.skip_current_dir();
+        }
+    }
+
+    dirs
+}
+
+/// If `command`'s first token looks like a script path (it contains a `/`), return it.
+fn referenced_script(command: &str) -> Option<&str> {
+    let first = command.split_whitespace().next()?;
+    (first.starts_with("./") || first.starts_with("../") || first.contains('/')).then_some(first)
+}
+
+/// Warn about module ids that are declared more than once across the tree.
+fn validate_unique_ids(modules: &[Module]) {
+    let mut seen: HashMap<&str, &Path> = HashMap::new();
+
+    for module in modules {
+        match seen.get(module.id.as_str()) {
+            Some(first_root) => {
+                let display_path = module.root.join("module.toml").display().to_string();
+                let source = fs::read_to_string(&display_path).unwrap_or_default();
+                diagnostics::report_validation_error(
+                    &display_path,
+                    &source,
+                    format!(
+                        "duplicate module id `{}` (also defined in {})",
+                        module.id,
+                        first_root.display()
+                    ),
+                );
+            }
+            None => {
+                seen.insert(&module.id, &module.root);
+            }
+        }
+    }
+}
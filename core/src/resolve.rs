@@ -0,0 +1,67 @@
+//! Fuzzy name resolution shared by every front end (TUI command palette, future
+//! headless CLI) that lets a user type a module or action id directly instead of
+//! navigating to it.
+
+/// Outcome of resolving a user-typed name against a set of known candidate ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// `name` matched a known id exactly.
+    Exact(String),
+    /// No exact match, but exactly one candidate was close enough to suggest.
+    Suggestion(String),
+    /// No exact match, and either no candidate was close enough or more than one tied.
+    None,
+}
+
+/// Resolve `name` against `candidates`: an exact match wins outright; otherwise the
+/// closest candidate is suggested as long as its edit distance is within `max(3, len/3)`
+/// of `name` and strictly closer than every other candidate.
+pub fn resolve(name: &str, candidates: &[String]) -> Resolution {
+    if candidates.iter().any(|candidate| candidate == name) {
+        return Resolution::Exact(name.to_string());
+    }
+
+    let threshold = (name.chars().count() / 3).max(3);
+    let mut best: Option<(usize, &str)> = None;
+    let mut tied = false;
+
+    for candidate in candidates {
+        let distance = levenshtein(name, candidate);
+        match best {
+            Some((best_distance, _)) if distance < best_distance => {
+                best = Some((distance, candidate));
+                tied = false;
+            }
+            Some((best_distance, _)) if distance == best_distance => tied = true,
+            None => best = Some((distance, candidate)),
+            _ => {}
+        }
+    }
+
+    match best {
+        Some((distance, candidate)) if distance <= threshold && !tied => {
+            Resolution::Suggestion(candidate.to_string())
+        }
+        _ => Resolution::None,
+    }
+}
+
+/// Levenshtein edit distance between two strings, compared character by character.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for (i, &a_ch) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, &b_ch) in b.iter().enumerate() {
+            let cost = usize::from(a_ch != b_ch);
+            curr[j + 1] = (curr[j] + 1).min(prev[j + 1] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
@@ -1,25 +1,175 @@
 //! Command runner abstractions.
 
-use crate::{Error, Result};
+use crate::registry::Module;
+use crate::{integrity, Error, Result};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Which stream a captured output line came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// A single line of output captured while a command runs.
+#[derive(Debug, Clone)]
+pub struct OutputLine {
+    pub stream: Stream,
+    pub text: String,
+}
+
+/// The result of a finished command: its exit code and how long it took.
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    pub exit_code: i32,
+    pub duration: Duration,
+}
 
 /// Trait describing how to execute commands for modules.
 pub trait CommandRunner {
-    fn run(&self, command: &str) -> Result<()>;
+    /// Execute `action`'s `command` for `module`, invoking `on_line` for
+    /// every line of stdout/stderr as it is produced.
+    ///
+    /// If `module` pins `action` to a checksum, the referenced script is
+    /// re-hashed first; the run is refused with `Error::ChecksumMismatch`
+    /// unless `allow_unverified` is set. Callers running an individual pipeline
+    /// step must pass that step's own `"{pipeline}#{step index}"` checksum key
+    /// here rather than the pipeline's name, or every step collides on one digest.
+    ///
+    /// `env` is exported to the child process as additional environment
+    /// variables, e.g. values collected for the action's declared `params`.
+    ///
+    /// The child process is killed early if `cancel` is set to `true` while
+    /// the command is still running (e.g. by a caller on another thread).
+    fn run(
+        &self,
+        module: &Module,
+        action: &str,
+        command: &str,
+        env: &HashMap<String, String>,
+        allow_unverified: bool,
+        on_line: &mut dyn FnMut(OutputLine),
+        cancel: &AtomicBool,
+    ) -> Result<RunOutcome>;
 }
 
-/// A command runner that simply reminds the user to implement real logic.
-pub struct NoopRunner;
+/// A command runner that spawns module actions as real child processes.
+pub struct ProcessRunner;
 
-impl CommandRunner for NoopRunner {
-    fn run(&self, command: &str) -> Result<()> {
-        println!("TODO: run command `{}`", command);
-        Err(Error::Unimplemented)
+impl ProcessRunner {
+    /// Helper constructor for the process runner.
+    pub fn new() -> Self {
+        Self
     }
 }
 
-impl NoopRunner {
-    /// Helper constructor for the noop runner.
-    pub fn new() -> Self {
-        Self
+impl Default for ProcessRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandRunner for ProcessRunner {
+    fn run(
+        &self,
+        module: &Module,
+        action: &str,
+        command: &str,
+        env: &HashMap<String, String>,
+        allow_unverified: bool,
+        on_line: &mut dyn FnMut(OutputLine),
+        cancel: &AtomicBool,
+    ) -> Result<RunOutcome> {
+        if let Some(expected) = module.checksums.get(action) {
+            let script = command.split_whitespace().next().unwrap_or(command);
+            let script_path = module.root.join(script);
+            integrity::verify_script(&script_path, expected, allow_unverified)?;
+        }
+
+        let started = Instant::now();
+
+        let mut child = Command::new(interpreter_for(&module.script_kind))
+            .arg("-c")
+            .arg(command)
+            .current_dir(&module.root)
+            .envs(env)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let stdout = child.stdout.take().expect("stdout was piped");
+        let stderr = child.stderr.take().expect("stderr was piped");
+
+        let (tx, rx) = mpsc::channel();
+
+        let stdout_tx = tx.clone();
+        let stdout_handle = thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if stdout_tx.send(OutputLine { stream: Stream::Stdout, text: line }).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let stderr_handle = thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if tx.send(OutputLine { stream: Stream::Stderr, text: line }).is_err() {
+                    break;
+                }
+            }
+        });
+
+        loop {
+            match rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(line) => on_line(line),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            // Checked every iteration, not just on an idle timeout, so a child that streams
+            // output continuously (no quiet gap) still notices cancellation promptly.
+            if cancel.load(Ordering::Relaxed) {
+                let _ = child.kill();
+            }
+        }
+
+        let _ = stdout_handle.join();
+        let _ = stderr_handle.join();
+
+        let status = child.wait()?;
+        let duration = started.elapsed();
+
+        if cancel.load(Ordering::Relaxed) {
+            on_line(OutputLine { stream: Stream::Stderr, text: format!("✗ `{command}` cancelled after {duration:.2?}") });
+            return Err(Error::CommandFailed { code: status.code().unwrap_or(-1), command: command.to_string() });
+        }
+
+        let exit_code = status.code().unwrap_or(-1);
+
+        if exit_code == 0 {
+            on_line(OutputLine { stream: Stream::Stdout, text: format!("✓ `{command}` completed in {duration:.2?}") });
+            Ok(RunOutcome { exit_code, duration })
+        } else {
+            on_line(OutputLine { stream: Stream::Stderr, text: format!("✗ `{command}` failed with exit code {exit_code}") });
+            Err(Error::CommandFailed { code: exit_code, command: command.to_string() })
+        }
+    }
+}
+
+/// Map a module's declared `script_kind` to the interpreter binary that should run its
+/// `-c <command>` action commands. An unset kind falls back to `sh`; any other kind is
+/// used as the interpreter binary name as-is.
+fn interpreter_for(script_kind: &str) -> &str {
+    match script_kind {
+        "bash" => "bash",
+        "zsh" => "zsh",
+        "" | "sh" => "sh",
+        other => other,
     }
 }
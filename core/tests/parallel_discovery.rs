@@ -0,0 +1,51 @@
+//! Integration test covering discovery correctness against a large synthetic catalog.
+//! Run with `cargo test --features integration`.
+#![cfg(feature = "integration")]
+
+use std::fs;
+use toolbox_core::registry;
+
+#[test]
+fn sequential_and_parallel_discovery_agree_on_hundreds_of_modules() {
+    let root = std::env::temp_dir().join(format!("wpsi_utils-parallel-discovery-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+
+    let categories = ["Applications", "Security", "System", "Gaming"];
+    let module_count = 400;
+
+    for i in 0..module_count {
+        let category = categories[i % categories.len()];
+        let module_dir = root.join(category).join(format!("module-{i:04}"));
+        fs::create_dir_all(&module_dir).unwrap();
+        fs::write(
+            module_dir.join("module.toml"),
+            format!(
+                "id = \"module-{i:04}\"\n\
+                 name = \"Module {i:04}\"\n\
+                 description = \"synthetic module\"\n\
+                 category = \"{category}\"\n\
+                 script_kind = \"bash\"\n\
+                 enabled = true\n"
+            ),
+        )
+        .unwrap();
+    }
+
+    let sequential = registry::discover_modules(&root).unwrap();
+    let parallel = registry::discover_modules_parallel(&root).unwrap();
+
+    assert_eq!(sequential.len(), module_count);
+    assert_eq!(parallel.len(), module_count);
+
+    let mut sequential_ids: Vec<_> = sequential.iter().map(|m| m.id.clone()).collect();
+    let mut parallel_ids: Vec<_> = parallel.iter().map(|m| m.id.clone()).collect();
+    sequential_ids.sort();
+    parallel_ids.sort();
+    assert_eq!(sequential_ids, parallel_ids);
+
+    for pair in parallel.windows(2) {
+        assert!((&pair[0].category, &pair[0].name) <= (&pair[1].category, &pair[1].name));
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
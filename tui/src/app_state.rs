@@ -1,13 +1,56 @@
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
-use toolbox_core::registry;
+use toolbox_core::format;
+use toolbox_core::registry::{self, ActionParam, Pipeline, StepCondition};
+use toolbox_core::resolve::{self, Resolution};
+use toolbox_core::runner::{CommandRunner, OutputLine, ProcessRunner, RunOutcome, Stream};
+
+use crate::fuzzy;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Focus {
     Categories,
     Modules,
     Actions,
+    /// Filling in an action's declared parameters before it runs.
+    Params,
+    /// Viewing the scrollable output of a running or just-finished action.
+    Output,
+}
+
+/// What an action name resolves to: either a single templated command or a pipeline
+/// of ordered, conditionally-run steps.
+#[derive(Clone)]
+enum ActivationTarget {
+    Command(String),
+    Pipeline(Pipeline),
+}
+
+/// An action selected to run, waiting on parameter values before it is spawned.
+struct PendingAction {
+    module: registry::Module,
+    action: String,
+    target: ActivationTarget,
+    params: Vec<ActionParam>,
+    values: Vec<String>,
+    field_index: usize,
+}
+
+/// An event pushed from the background job thread to the UI thread each tick.
+enum JobEvent {
+    Line(OutputLine),
+    Done(Result<RunOutcome, toolbox_core::Error>),
+}
+
+/// A still-running (or just-finished, until drained) action.
+struct RunningJob {
+    action: String,
+    cancel: Arc<AtomicBool>,
+    events: mpsc::Receiver<JobEvent>,
 }
 
 pub struct App {
@@ -19,10 +62,30 @@ pub struct App {
     focus: Focus,
     status: String,
     modules_root: PathBuf,
+    /// The panel to return to when leaving the output overlay.
+    return_focus: Focus,
+    /// Captured stdout/stderr lines from the most recent action run.
+    output: Vec<String>,
+    /// Index of the topmost visible output line.
+    output_scroll: usize,
+    running: Option<RunningJob>,
+    /// Fuzzy filter query narrowing categories/modules/actions. Empty means unfiltered.
+    filter: String,
+    /// Whether keystrokes are currently being captured into `filter`.
+    filter_active: bool,
+    /// An action awaiting parameter input, if `focus` is `Focus::Params`.
+    pending: Option<PendingAction>,
+    /// Whether keystrokes are currently being captured into `palette`.
+    palette_active: bool,
+    /// Command-palette text: a module or action id typed directly, launched by name.
+    palette: String,
+    /// Whether a pinned-but-mismatched script is allowed to run anyway,
+    /// mirroring `Config.allow_unverified`.
+    allow_unverified: bool,
 }
 
 impl App {
-    pub fn new(modules: Vec<registry::Module>, modules_root: PathBuf) -> Self {
+    pub fn new(modules: Vec<registry::Module>, modules_root: PathBuf, allow_unverified: bool) -> Self {
         let mut map: BTreeMap<String, Vec<registry::Module>> = BTreeMap::new();
 
         if modules.is_empty() {
@@ -59,6 +122,16 @@ impl App {
             focus: Focus::Categories,
             status: String::from("Ready. Use Tab to switch panels."),
             modules_root,
+            return_focus: Focus::Actions,
+            output: Vec::new(),
+            output_scroll: 0,
+            running: None,
+            filter: String::new(),
+            filter_active: false,
+            pending: None,
+            palette_active: false,
+            palette: String::new(),
+            allow_unverified,
         };
         app.ensure_indices();
         app
@@ -68,12 +141,169 @@ impl App {
         &self.modules_root
     }
 
-    pub fn categories(&self) -> &[String] {
-        &self.categories
+    /// Categories matching the active filter (all of them, in their original order, if
+    /// there is no filter), ranked best-match first.
+    pub fn categories(&self) -> Vec<String> {
+        fuzzy::rank(&self.filter, &self.categories).into_iter().map(|index| self.categories[index].clone()).collect()
+    }
+
+    pub fn current_category_name(&self) -> Option<String> {
+        self.categories().get(self.category_index).cloned()
+    }
+
+    /// The current filter query, or an empty string when unfiltered.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Whether keystrokes are currently being captured as filter text.
+    pub fn is_filtering(&self) -> bool {
+        self.filter_active
+    }
+
+    /// Enter filter-entry mode, clearing any previous query.
+    pub fn start_filter(&mut self) {
+        self.filter_active = true;
+        self.filter.clear();
+        self.ensure_indices();
+    }
+
+    /// Append a character to the filter query.
+    pub fn push_filter_char(&mut self, ch: char) {
+        self.filter.push(ch);
+        self.ensure_indices();
+    }
+
+    /// Remove the last character of the filter query, if any.
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.ensure_indices();
+    }
+
+    /// Leave filter-entry mode, keeping the current query applied to the lists.
+    pub fn confirm_filter(&mut self) {
+        self.filter_active = false;
+    }
+
+    /// Leave filter-entry mode and clear the query, restoring the unfiltered lists.
+    pub fn cancel_filter(&mut self) {
+        self.filter_active = false;
+        self.filter.clear();
+        self.ensure_indices();
+    }
+
+    /// Whether keystrokes are currently being captured as command-palette text.
+    pub fn is_palette_active(&self) -> bool {
+        self.palette_active
+    }
+
+    /// The in-progress command-palette text.
+    pub fn palette(&self) -> &str {
+        &self.palette
+    }
+
+    /// Open the command palette, clearing any previous query.
+    pub fn start_palette(&mut self) {
+        self.palette_active = true;
+        self.palette.clear();
+    }
+
+    /// Append a character to the palette query.
+    pub fn push_palette_char(&mut self, ch: char) {
+        self.palette.push(ch);
+    }
+
+    /// Remove the last character of the palette query, if any.
+    pub fn pop_palette_char(&mut self) {
+        self.palette.pop();
+    }
+
+    /// Close the palette without resolving its text.
+    pub fn cancel_palette(&mut self) {
+        self.palette_active = false;
+        self.palette.clear();
+    }
+
+    /// Resolve the typed palette text: jump to (and, for an action, launch) an exact
+    /// match, or report a suggestion/no-match in `status` otherwise.
+    pub fn confirm_palette(&mut self) {
+        self.palette_active = false;
+        let name = std::mem::take(&mut self.palette);
+        if name.is_empty() {
+            return;
+        }
+
+        match self.resolve(&name) {
+            Resolution::Exact(id) => self.launch_by_name(&id),
+            Resolution::Suggestion(candidate) => {
+                self.status = format!("Unknown `{name}` — did you mean `{candidate}`?");
+            }
+            Resolution::None => {
+                self.status = format!("Unknown module or action `{name}`.");
+            }
+        }
     }
 
-    pub fn current_category_name(&self) -> Option<&str> {
-        self.categories.get(self.category_index).map(|category| category.as_str())
+    /// Every module id and action/pipeline name known across the whole catalog — the
+    /// candidate pool for [`App::resolve`] and the command palette.
+    fn all_names(&self) -> Vec<String> {
+        self.modules_by_category
+            .values()
+            .flatten()
+            .flat_map(|module| {
+                std::iter::once(module.id.clone()).chain(module.actions.keys().cloned()).chain(module.pipelines.keys().cloned())
+            })
+            .collect()
+    }
+
+    /// Resolve a user-typed module or action id against the whole catalog: an exact
+    /// match, a single close "did you mean" suggestion, or an ambiguous/no match.
+    pub fn resolve(&self, name: &str) -> Resolution {
+        resolve::resolve(name, &self.all_names())
+    }
+
+    /// Jump to the module with id `id`, or to the module/action pair owning an action
+    /// named `id`, running it immediately in the latter case.
+    fn launch_by_name(&mut self, id: &str) {
+        let categories = self.categories.clone();
+        let mut found: Option<(usize, usize, bool)> = None;
+
+        'outer: for (category_index, category) in categories.iter().enumerate() {
+            let Some(modules) = self.modules_by_category.get(category) else { continue };
+            for (module_index, module) in modules.iter().enumerate() {
+                if module.id == id {
+                    found = Some((category_index, module_index, true));
+                    break 'outer;
+                }
+                if module.actions.contains_key(id) || module.pipelines.contains_key(id) {
+                    found = Some((category_index, module_index, false));
+                    break 'outer;
+                }
+            }
+        }
+
+        let Some((category_index, module_index, is_module)) = found else {
+            self.status = format!("Unknown module or action `{id}`.");
+            return;
+        };
+
+        self.category_index = category_index;
+        self.module_index = module_index;
+        self.filter.clear();
+        self.ensure_indices();
+
+        if is_module {
+            self.action_index = 0;
+            self.focus = Focus::Modules;
+            self.status = format!("Jumped to module `{id}`.");
+            return;
+        }
+
+        if let Some(index) = self.current_actions().iter().position(|(name, _)| name == id) {
+            self.action_index = index;
+        }
+        self.focus = Focus::Actions;
+        self.activate();
     }
 
     pub fn category_index(&self) -> usize {
@@ -96,27 +326,53 @@ impl App {
         &self.status
     }
 
+    /// Captured output lines from the most recent (or still-running) action.
+    pub fn output(&self) -> &[String] {
+        &self.output
+    }
+
+    pub fn output_scroll(&self) -> usize {
+        self.output_scroll
+    }
+
+    /// Whether an action is currently running in the background.
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
     pub fn current_modules(&self) -> Vec<&registry::Module> {
-        self.categories
-            .get(self.category_index)
-            .and_then(|category| self.modules_by_category.get(category))
-            .map(|modules| modules.iter().collect())
-            .unwrap_or_default()
+        let Some(category) = self.current_category_name() else { return Vec::new() };
+        let Some(modules) = self.modules_by_category.get(&category) else { return Vec::new() };
+
+        let names: Vec<&str> = modules.iter().map(|module| module.name.as_str()).collect();
+        fuzzy::rank(&self.filter, &names).into_iter().map(|index| &modules[index]).collect()
     }
 
+    /// Every runnable action of the current module, as `(name, display)` pairs. A plain
+    /// action displays its command; a pipeline displays its step count, taking
+    /// precedence over a same-named plain action.
     pub fn current_actions(&self) -> Vec<(String, String)> {
-        self.current_modules()
-            .get(self.module_index)
-            .map(|module| {
-                let mut actions: Vec<_> = module
-                    .actions
-                    .iter()
-                    .map(|(name, path)| (name.clone(), path.clone()))
-                    .collect();
-                actions.sort_by(|a, b| a.0.cmp(&b.0));
-                actions
+        let Some(module) = self.current_modules().into_iter().nth(self.module_index) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<&String> = module.actions.keys().chain(module.pipelines.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let actions: Vec<(String, String)> = names
+            .into_iter()
+            .map(|name| {
+                let display = match module.pipelines.get(name) {
+                    Some(pipeline) => format!("<pipeline, {} step(s)>", pipeline.steps.len()),
+                    None => module.actions.get(name).cloned().unwrap_or_default(),
+                };
+                (name.clone(), display)
             })
-            .unwrap_or_default()
+            .collect();
+
+        let search_names: Vec<&str> = actions.iter().map(|(name, _)| name.as_str()).collect();
+        fuzzy::rank(&self.filter, &search_names).into_iter().map(|index| actions[index].clone()).collect()
     }
 
     pub fn focus_next(&mut self) {
@@ -138,6 +394,8 @@ impl App {
                 }
             }
             Focus::Actions => Focus::Categories,
+            Focus::Params => Focus::Params,
+            Focus::Output => Focus::Output,
         };
     }
 
@@ -152,6 +410,8 @@ impl App {
                     Focus::Categories
                 }
             }
+            Focus::Params => Focus::Params,
+            Focus::Output => Focus::Output,
         };
     }
 
@@ -175,6 +435,12 @@ impl App {
                     self.action_index -= 1;
                 }
             }
+            Focus::Params => self.param_prev_field(),
+            Focus::Output => {
+                if self.output_scroll > 0 {
+                    self.output_scroll -= 1;
+                }
+            }
         }
         self.ensure_indices();
     }
@@ -182,7 +448,7 @@ impl App {
     pub fn move_down(&mut self) {
         match self.focus {
             Focus::Categories => {
-                if self.category_index + 1 < self.categories.len() {
+                if self.category_index + 1 < self.categories_len() {
                     self.category_index += 1;
                     self.module_index = 0;
                     self.action_index = 0;
@@ -201,50 +467,336 @@ impl App {
                     self.action_index += 1;
                 }
             }
+            Focus::Params => self.param_next_field(),
+            Focus::Output => {
+                let max_scroll = self.output.len().saturating_sub(1);
+                if self.output_scroll < max_scroll {
+                    self.output_scroll += 1;
+                }
+            }
         }
         self.ensure_indices();
     }
 
+    /// Select the action under the cursor: run it directly, or — if it declares
+    /// `params` — open the parameter form to collect values first.
     pub fn activate(&mut self) {
         if self.focus != Focus::Actions {
             self.status = String::from("Select an action and press Enter to run it.");
             return;
         }
 
-        if let Some((name, command)) = self.current_actions().get(self.action_index).cloned() {
-            self.status = format!("TODO: run `{command}` ({name})");
-        } else {
+        if self.running.is_some() {
+            self.status = String::from("An action is already running. Cancel it before starting another.");
+            return;
+        }
+
+        let Some(module) = self.current_modules().get(self.module_index).map(|m| (*m).clone()) else {
+            self.status = String::from("No module selected.");
+            return;
+        };
+
+        let Some((action, _)) = self.current_actions().get(self.action_index).cloned() else {
             self.status = String::from("No actions available for this module.");
+            return;
+        };
+
+        let target = if let Some(pipeline) = module.pipelines.get(&action).cloned() {
+            ActivationTarget::Pipeline(pipeline)
+        } else if let Some(command) = module.actions.get(&action).cloned() {
+            ActivationTarget::Command(command)
+        } else {
+            self.status = format!("Action `{action}` has no command or pipeline.");
+            return;
+        };
+
+        let params = module.params.get(&action).cloned().unwrap_or_default();
+        if params.is_empty() {
+            self.run_target(module, action, target, HashMap::new());
+            return;
+        }
+
+        let values = params.iter().map(|param| param.default.clone().unwrap_or_default()).collect();
+        self.pending = Some(PendingAction { module, action, target, params, values, field_index: 0 });
+        self.return_focus = Focus::Actions;
+        self.focus = Focus::Params;
+        self.status = String::from("Fill in the parameters below, then press Enter to run.");
+    }
+
+    /// Spawn the resolved action (a single command or a pipeline) as a background job,
+    /// streaming its output into the output overlay. Each command is templated first,
+    /// the same as `cli`'s `--run` path, so `{module_root}`-style placeholders resolve
+    /// before the shell ever sees them.
+    fn run_target(&mut self, module: registry::Module, action: String, target: ActivationTarget, env: HashMap<String, String>) {
+        let context = format::builtin_context(&module.root.display().to_string(), &module.id, &module.category);
+
+        match target {
+            ActivationTarget::Command(command) => match format::expand(&command, &context) {
+                Ok(command) => self.spawn_action(module, action, command, env),
+                Err(err) => self.status = format!("Failed to expand action `{action}`: {err}"),
+            },
+            ActivationTarget::Pipeline(mut pipeline) => {
+                for step in &mut pipeline.steps {
+                    match format::expand(&step.command, &context) {
+                        Ok(expanded) => step.command = expanded,
+                        Err(err) => {
+                            self.status = format!("Failed to expand action `{action}`: {err}");
+                            return;
+                        }
+                    }
+                }
+                self.spawn_pipeline(module, action, pipeline, env)
+            }
         }
     }
 
+    /// Spawn `command` as a background child process, streaming its output into
+    /// the output overlay.
+    fn spawn_action(&mut self, module: registry::Module, action: String, command: String, env: HashMap<String, String>) {
+        let (tx, rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let thread_cancel = Arc::clone(&cancel);
+        let job_action = action.clone();
+        let allow_unverified = self.allow_unverified;
+
+        thread::spawn(move || {
+            let runner = ProcessRunner::new();
+            let line_tx = tx.clone();
+            let result = runner.run(
+                &module,
+                &action,
+                &command,
+                &env,
+                allow_unverified,
+                &mut |line| {
+                    let _ = line_tx.send(JobEvent::Line(line));
+                },
+                &thread_cancel,
+            );
+            let _ = tx.send(JobEvent::Done(result));
+        });
+
+        self.return_focus = Focus::Actions;
+        self.focus = Focus::Output;
+        self.output.clear();
+        self.output_scroll = 0;
+        self.status = String::from("Running...");
+        self.running = Some(RunningJob { action: job_action, cancel, events: rx });
+    }
+
+    /// Run a pipeline's steps in order on a background thread, evaluating each step's
+    /// `when` condition against prior step results and the collected `env`, and
+    /// reporting per-step pending/running/ok/failed/skipped status into the output pane.
+    fn spawn_pipeline(&mut self, module: registry::Module, action: String, pipeline: Pipeline, env: HashMap<String, String>) {
+        let (tx, rx) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let thread_cancel = Arc::clone(&cancel);
+        let job_action = action.clone();
+        let allow_unverified = self.allow_unverified;
+
+        let send_status = {
+            let tx = tx.clone();
+            move |index: usize, total: usize, label: &str| {
+                let _ = tx.send(JobEvent::Line(OutputLine {
+                    stream: Stream::Stdout,
+                    text: format!("== step {}/{total}: {label} ==", index + 1),
+                }));
+            }
+        };
+
+        thread::spawn(move || {
+            let runner = ProcessRunner::new();
+            let total = pipeline.steps.len();
+            let mut succeeded: Vec<Option<bool>> = vec![None; total];
+            let started = std::time::Instant::now();
+            let mut last_code = 0;
+            let mut any_failed = false;
+
+            for index in 0..total {
+                send_status(index, total, "pending");
+            }
+
+            for (index, step) in pipeline.steps.iter().enumerate() {
+                if let Some(condition) = &step.when {
+                    if !evaluate_condition(condition, &succeeded, &env) {
+                        send_status(index, total, "skipped");
+                        continue;
+                    }
+                }
+
+                send_status(index, total, "running");
+                let line_tx = tx.clone();
+                let step_checksum_key = format!("{action}#{index}");
+                let result = runner.run(
+                    &module,
+                    &step_checksum_key,
+                    &step.command,
+                    &env,
+                    allow_unverified,
+                    &mut |line| {
+                        let _ = line_tx.send(JobEvent::Line(line));
+                    },
+                    &thread_cancel,
+                );
+
+                match result {
+                    Ok(outcome) => {
+                        succeeded[index] = Some(true);
+                        last_code = outcome.exit_code;
+                        send_status(index, total, "ok");
+                    }
+                    Err(err) => {
+                        succeeded[index] = Some(false);
+                        last_code = 1;
+                        any_failed = true;
+                        send_status(index, total, &format!("failed ({err})"));
+                        if pipeline.stop_on_failure || thread_cancel.load(Ordering::Relaxed) {
+                            break;
+                        }
+                    }
+                }
+
+                if thread_cancel.load(Ordering::Relaxed) {
+                    break;
+                }
+            }
+
+            if any_failed && last_code == 0 {
+                last_code = 1;
+            }
+            let outcome = RunOutcome { exit_code: last_code, duration: started.elapsed() };
+            let _ = tx.send(JobEvent::Done(Ok(outcome)));
+        });
+
+        self.return_focus = Focus::Actions;
+        self.focus = Focus::Output;
+        self.output.clear();
+        self.output_scroll = 0;
+        self.status = String::from("Running pipeline...");
+        self.running = Some(RunningJob { action: job_action, cancel, events: rx });
+    }
+
+    /// The parameters and in-progress values of the action awaiting input, if any.
+    pub fn pending_params(&self) -> Option<(&[ActionParam], &[String], usize)> {
+        self.pending.as_ref().map(|pending| (pending.params.as_slice(), pending.values.as_slice(), pending.field_index))
+    }
+
+    /// Move to the previous parameter field, wrapping around.
+    pub fn param_prev_field(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            let len = pending.params.len();
+            pending.field_index = (pending.field_index + len - 1) % len;
+        }
+    }
+
+    /// Move to the next parameter field, wrapping around.
+    pub fn param_next_field(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            pending.field_index = (pending.field_index + 1) % pending.params.len();
+        }
+    }
+
+    /// Append a character to the currently focused parameter field.
+    pub fn push_param_char(&mut self, ch: char) {
+        if let Some(pending) = &mut self.pending {
+            pending.values[pending.field_index].push(ch);
+        }
+    }
+
+    /// Remove the last character of the currently focused parameter field.
+    pub fn pop_param_char(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            pending.values[pending.field_index].pop();
+        }
+    }
+
+    /// Abandon the parameter form without running the action.
+    pub fn cancel_params(&mut self) {
+        self.pending = None;
+        self.focus = self.return_focus;
+        self.status = String::from("Cancelled.");
+    }
+
+    /// Export the collected parameter values as environment variables and run the action.
+    pub fn confirm_params(&mut self) {
+        let Some(pending) = self.pending.take() else { return };
+        let env = pending
+            .params
+            .iter()
+            .zip(pending.values.iter())
+            .map(|(param, value)| (param.name.clone(), value.clone()))
+            .collect();
+        self.run_target(pending.module, pending.action, pending.target, env);
+    }
+
+    /// Request cancellation of the currently running action, if any.
+    pub fn cancel_running(&mut self) {
+        if let Some(running) = &self.running {
+            running.cancel.store(true, Ordering::Relaxed);
+            self.status = String::from("Cancelling...");
+        }
+    }
+
+    /// Leave the output overlay and return to the panel that launched it.
+    /// Has no effect while an action is still running.
+    pub fn leave_output(&mut self) {
+        if self.focus == Focus::Output && self.running.is_none() {
+            self.focus = self.return_focus;
+        }
+    }
+
+    /// Drain any pending output/completion events from the background job. Call once per
+    /// UI tick so the output overlay reflects progress without blocking the event loop.
+    pub fn tick(&mut self) {
+        let Some(running) = &self.running else { return };
+
+        let mut finished = None;
+        while let Ok(event) = running.events.try_recv() {
+            match event {
+                JobEvent::Line(line) => {
+                    let prefix = match line.stream {
+                        Stream::Stdout => "",
+                        Stream::Stderr => "! ",
+                    };
+                    self.output.push(format!("{prefix}{}", line.text));
+                }
+                JobEvent::Done(result) => finished = Some(result),
+            }
+        }
+
+        if let Some(result) = finished {
+            let action = running.action.clone();
+            self.status = match result {
+                Ok(outcome) => format!("`{action}` exited {} in {:.2?}", outcome.exit_code, outcome.duration),
+                Err(err) => format!("`{action}` failed: {err}"),
+            };
+            self.running = None;
+        }
+    }
+
+    fn categories_len(&self) -> usize {
+        fuzzy::rank(&self.filter, &self.categories).len()
+    }
+
     fn current_modules_len(&self) -> usize {
-        self.categories
-            .get(self.category_index)
-            .and_then(|category| self.modules_by_category.get(category))
-            .map(|modules| modules.len())
-            .unwrap_or(0)
+        self.current_modules().len()
     }
 
     fn current_actions_len(&self) -> usize {
-        self.categories
-            .get(self.category_index)
-            .and_then(|category| self.modules_by_category.get(category))
-            .and_then(|modules| modules.get(self.module_index))
-            .map(|module| module.actions.len())
-            .unwrap_or(0)
+        self.current_actions().len()
     }
 
     fn ensure_indices(&mut self) {
-        if self.categories.is_empty() {
+        let categories_len = self.categories_len();
+        if categories_len == 0 {
             self.category_index = 0;
             self.module_index = 0;
             self.action_index = 0;
             return;
         }
 
-        if self.category_index >= self.categories.len() {
-            self.category_index = self.categories.len() - 1;
+        if self.category_index >= categories_len {
+            self.category_index = categories_len - 1;
         }
 
         let modules_len = self.current_modules_len();
@@ -263,3 +815,13 @@ impl App {
         }
     }
 }
+
+/// Decide whether a pipeline step's `when` condition holds, given the pass/fail outcome
+/// of each prior step (`None` for steps not yet reached) and the collected parameter env.
+fn evaluate_condition(condition: &StepCondition, succeeded: &[Option<bool>], env: &HashMap<String, String>) -> bool {
+    match condition {
+        StepCondition::StepSucceeded(index) => succeeded.get(*index).copied().flatten().unwrap_or(false),
+        StepCondition::StepFailed(index) => succeeded.get(*index).copied().flatten().map(|ok| !ok).unwrap_or(false),
+        StepCondition::ParamEquals { param, value } => env.get(param).is_some_and(|actual| actual == value),
+    }
+}
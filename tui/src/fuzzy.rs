@@ -0,0 +1,74 @@
+//! Subsequence fuzzy matching used to filter the TUI's lists as the user types.
+
+/// Score `candidate` against `query` using case-insensitive subsequence matching.
+///
+/// Returns `None` when `query`'s characters don't all appear in `candidate`, in order
+/// (skipping characters as needed). Otherwise returns a score where higher is a better
+/// match: consecutive matched runs and matches at word boundaries (the start of
+/// `candidate`, or just after a space, `-`, or `_`) are rewarded, while the gap between
+/// consecutively matched characters is penalized. An empty `query` matches everything
+/// with a score of `0`.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let candidate_lower: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+
+    let mut query_pos = 0;
+    let mut last_match: Option<usize> = None;
+    let mut run_length = 0i64;
+    let mut total = 0i64;
+
+    for (index, &ch) in candidate_lower.iter().enumerate() {
+        if query_pos >= query.len() {
+            break;
+        }
+        if ch != query[query_pos] {
+            continue;
+        }
+
+        let at_boundary = index == 0 || matches!(candidate_chars[index - 1], ' ' | '-' | '_');
+        let mut points = 10;
+        if at_boundary {
+            points += 15;
+        }
+
+        if let Some(last) = last_match {
+            let gap = (index - last - 1) as i64;
+            if gap == 0 {
+                run_length += 1;
+                points += 5 + run_length * 3;
+            } else {
+                run_length = 0;
+                points -= gap;
+            }
+        }
+
+        total += points;
+        last_match = Some(index);
+        query_pos += 1;
+    }
+
+    (query_pos == query.len()).then_some(total)
+}
+
+/// Filter and rank `candidates` against `query`, returning the original indices of the
+/// matching candidates in descending score order (ties broken by the candidate's natural
+/// `Ord`). An empty `query` returns every index in its original order.
+pub fn rank<T: AsRef<str>>(query: &str, candidates: &[T]) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..candidates.len()).collect();
+    }
+
+    let mut scored: Vec<(i64, usize)> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| score(query, candidate.as_ref()).map(|s| (s, index)))
+        .collect();
+
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| candidates[a.1].as_ref().cmp(candidates[b.1].as_ref())));
+    scored.into_iter().map(|(_, index)| index).collect()
+}
@@ -1,17 +1,16 @@
+mod app_state;
+mod fuzzy;
 mod ui;
 
+use app_state::{App, Focus};
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{self, Event, KeyCode, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{
-    env, io,
-    path::{Path, PathBuf},
-    time::Duration,
-};
-use toolbox_core::registry;
+use std::{env, io, path::PathBuf, time::Duration};
+use toolbox_core::{config, registry};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     enable_raw_mode()?;
@@ -20,8 +19,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let module_labels = load_module_labels();
-    let result = run(&mut terminal, &module_labels);
+    let modules_dir = env::var("WPSI_UTILS_MODULE_DIR").unwrap_or_else(|_| "modules".to_string());
+    let modules_path = PathBuf::from(&modules_dir);
+    let modules = match registry::discover_modules(&modules_path) {
+        Ok(modules) => modules,
+        Err(err) => {
+            eprintln!("Failed to discover modules in {}: {err}", modules_path.display());
+            Vec::new()
+        }
+    };
+
+    // Same `Config.allow_unverified` escape hatch the CLI reads from `--config`, since the
+    // TUI has no equivalent flag to thread it through.
+    let allow_unverified = env::var("WPSI_UTILS_CONFIG")
+        .ok()
+        .and_then(|path| config::load_config_profile(path, None, &[]).ok())
+        .map(|loaded| loaded.allow_unverified)
+        .unwrap_or(false);
+
+    let app = App::new(modules, modules_path, allow_unverified);
+    let result = run(&mut terminal, app);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -34,45 +51,69 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn load_module_labels() -> Vec<String> {
-    let modules_dir = env::var("WPSI_UTILS_MODULE_DIR").unwrap_or_else(|_| "modules".to_string());
-    let modules_path = PathBuf::from(&modules_dir);
-    match registry::discover_modules(&modules_path) {
-        Ok(modules) if !modules.is_empty() => modules
-            .into_iter()
-            .map(|module| {
-                let relative = module.root.strip_prefix(&modules_path).unwrap_or(&module.root);
-                format!("{} ({}) – {}", module.name, module.category, display_path(relative))
-            })
-            .collect(),
-        Ok(_) => default_modules(),
-        Err(err) => {
-            eprintln!("Failed to discover modules in {}: {err}", modules_path.display());
-            default_modules()
-        }
-    }
-}
+fn run<B: ratatui::prelude::Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+    loop {
+        app.tick();
+        terminal.draw(|f| ui::draw(f, &app))?;
 
-fn default_modules() -> Vec<String> {
-    vec!["Example Module A".into(), "Example Module B".into(), "Example Module C".into()]
-}
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    break;
+                }
 
-fn display_path(path: &Path) -> String {
-    path.display().to_string()
-}
+                if app.is_filtering() {
+                    match key.code {
+                        KeyCode::Char(ch) => app.push_filter_char(ch),
+                        KeyCode::Backspace => app.pop_filter_char(),
+                        KeyCode::Enter => app.confirm_filter(),
+                        KeyCode::Esc => app.cancel_filter(),
+                        _ => {}
+                    }
+                    continue;
+                }
 
-fn run<B: ratatui::prelude::Backend>(
-    terminal: &mut Terminal<B>,
-    modules: &[String],
-) -> io::Result<()> {
-    loop {
-        terminal.draw(|f| ui::draw(f, modules))?;
+                if app.is_palette_active() {
+                    match key.code {
+                        KeyCode::Char(ch) => app.push_palette_char(ch),
+                        KeyCode::Backspace => app.pop_palette_char(),
+                        KeyCode::Enter => app.confirm_palette(),
+                        KeyCode::Esc => app.cancel_palette(),
+                        _ => {}
+                    }
+                    continue;
+                }
 
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => break,
-                    _ => {}
+                match app.focus() {
+                    Focus::Output => match key.code {
+                        KeyCode::Char('q') => break,
+                        KeyCode::Char('c') => app.cancel_running(),
+                        KeyCode::Esc => app.leave_output(),
+                        KeyCode::Char('j') | KeyCode::Down => app.move_down(),
+                        KeyCode::Char('k') | KeyCode::Up => app.move_up(),
+                        _ => {}
+                    },
+                    Focus::Params => match key.code {
+                        KeyCode::Char(ch) => app.push_param_char(ch),
+                        KeyCode::Backspace => app.pop_param_char(),
+                        KeyCode::Tab | KeyCode::Down => app.param_next_field(),
+                        KeyCode::BackTab | KeyCode::Up => app.param_prev_field(),
+                        KeyCode::Enter => app.confirm_params(),
+                        KeyCode::Esc => app.cancel_params(),
+                        _ => {}
+                    },
+                    _ => match key.code {
+                        KeyCode::Esc if !app.filter().is_empty() => app.cancel_filter(),
+                        KeyCode::Char('q') | KeyCode::Esc => break,
+                        KeyCode::Char('/') => app.start_filter(),
+                        KeyCode::Char(':') => app.start_palette(),
+                        KeyCode::Tab => app.focus_next(),
+                        KeyCode::BackTab => app.focus_prev(),
+                        KeyCode::Char('j') | KeyCode::Down => app.move_down(),
+                        KeyCode::Char('k') | KeyCode::Up => app.move_up(),
+                        KeyCode::Enter => app.activate(),
+                        _ => {}
+                    },
                 }
             }
         }
@@ -6,6 +6,8 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
     Frame,
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 const ACCENT: Color = Color::Rgb(232, 199, 95);
 const ACCENT_DIM: Color = Color::Rgb(180, 150, 72);
@@ -13,6 +15,7 @@ const PANEL_BG: Color = Color::Rgb(28, 31, 38);
 const PANEL_BG_ALT: Color = Color::Rgb(22, 24, 30);
 const TEXT_PRIMARY: Color = Color::Rgb(225, 225, 220);
 const TEXT_MUTED: Color = Color::Rgb(150, 153, 160);
+const SUCCESS: Color = Color::Rgb(130, 190, 120);
 
 pub fn draw(f: &mut Frame, app: &App) {
     f.render_widget(Clear, f.size());
@@ -42,6 +45,18 @@ pub fn draw(f: &mut Frame, app: &App) {
     render_modules(f, body_chunks[1], app);
     render_actions(f, body_chunks[2], app);
 
+    if app.focus() == Focus::Output {
+        render_output(f, layout[2], app);
+    }
+
+    if app.focus() == Focus::Params {
+        render_param_form(f, layout[2], app);
+    }
+
+    if app.is_palette_active() {
+        render_palette(f, layout[2], app);
+    }
+
     render_footer(f, layout[3], app);
 }
 
@@ -89,12 +104,28 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)].as_ref())
         .split(header_chunks[1]);
 
-    let search = Paragraph::new(vec![
+    let search_line = if app.is_filtering() {
+        Line::from(vec![
+            Span::styled("Search", Style::default().fg(ACCENT)),
+            Span::raw("  "),
+            Span::styled(format!("{}█", app.filter()), Style::default().fg(TEXT_PRIMARY)),
+        ])
+    } else if !app.filter().is_empty() {
+        Line::from(vec![
+            Span::styled("Search", Style::default().fg(ACCENT)),
+            Span::raw("  "),
+            Span::styled(format!("{} (Esc to clear)", app.filter()), Style::default().fg(TEXT_PRIMARY)),
+        ])
+    } else {
         Line::from(vec![
             Span::styled("Search", Style::default().fg(ACCENT)),
             Span::raw("  "),
             Span::styled("Press / to search", Style::default().fg(TEXT_MUTED)),
-        ]),
+        ])
+    };
+
+    let search = Paragraph::new(vec![
+        search_line,
         Line::from(vec![Span::styled(app.status(), Style::default().fg(TEXT_PRIMARY))]),
     ])
     .block(
@@ -128,6 +159,8 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_categories(f: &mut Frame, area: Rect, app: &App) {
+    let name_budget = (area.width as usize).saturating_sub(2 + 4);
+
     let items: Vec<ListItem> = app
         .categories()
         .iter()
@@ -146,7 +179,7 @@ fn render_categories(f: &mut Frame, area: Rect, app: &App) {
 
             let line = Line::from(vec![
                 Span::styled(format!("{} ", prefix), style),
-                Span::styled(category.clone(), style),
+                Span::styled(truncate_to_width(category, name_budget), style),
             ]);
             ListItem::new(line)
         })
@@ -175,6 +208,8 @@ fn render_categories(f: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_modules(f: &mut Frame, area: Rect, app: &App) {
+    let name_budget = (area.width as usize).saturating_sub(2 + 2);
+    let desc_budget = (area.width as usize).saturating_sub(2 + 4);
     let modules = app.current_modules();
     let items: Vec<ListItem> = if modules.is_empty() {
         vec![ListItem::new(Line::from(Span::styled(
@@ -207,19 +242,20 @@ fn render_modules(f: &mut Frame, area: Rect, app: &App) {
 
                 let mut lines = vec![Line::from(vec![
                     Span::styled(format!("{} ", if selected { ">" } else { " " }), name_style),
-                    Span::styled(module.name.clone(), name_style),
+                    Span::styled(truncate_to_width(&module.name, name_budget), name_style),
                 ])];
 
                 if !module.description.is_empty() {
                     lines.push(Line::from(Span::styled(
-                        format!("    {}", module.description),
+                        format!("    {}", truncate_to_width(&module.description, desc_budget)),
                         desc_style,
                     )));
                 }
 
                 if let Ok(relative) = module.root.strip_prefix(app.modules_root()) {
+                    let relative = relative.display().to_string();
                     lines.push(Line::from(Span::styled(
-                        format!("    {}", relative.display()),
+                        format!("    {}", truncate_to_width(&relative, desc_budget)),
                         desc_style,
                     )));
                 }
@@ -229,7 +265,7 @@ fn render_modules(f: &mut Frame, area: Rect, app: &App) {
             .collect()
     };
 
-    let title = app.current_category_name().unwrap_or("Modules").to_string();
+    let title = app.current_category_name().unwrap_or_else(|| "Modules".to_string());
 
     let block = Block::default()
         .title(Span::styled(
@@ -245,6 +281,9 @@ fn render_modules(f: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_actions(f: &mut Frame, area: Rect, app: &App) {
+    let interior = (area.width as usize).saturating_sub(2 + 4 + 2);
+    let name_budget = interior / 2;
+    let command_budget = interior.saturating_sub(name_budget);
     let actions = app.current_actions();
     let items: Vec<ListItem> = if actions.is_empty() {
         vec![ListItem::new(Line::from(Span::styled(
@@ -282,9 +321,11 @@ fn render_actions(f: &mut Frame, area: Rect, app: &App) {
                 };
 
                 let tag = short_tag(&name);
+                let name = truncate_to_width(&name, name_budget);
+                let command = truncate_to_width(&command, command_budget);
 
                 let line = Line::from(vec![
-                    Span::styled(format!("{:<3} ", tag), tag_style),
+                    Span::styled(format!("{} ", tag), tag_style),
                     Span::styled(name, name_style),
                     Span::raw("  "),
                     Span::styled(command, command_style),
@@ -317,39 +358,195 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
 
     let key_style = Style::default().fg(ACCENT).add_modifier(Modifier::BOLD);
 
-    let lines = vec![
-        Line::from(vec![
-            Span::styled("[g]", key_style),
-            Span::raw(" Show tabs    "),
-            Span::styled("[Ctrl+C]", key_style),
-            Span::raw(" Exit toolbox    "),
-            Span::styled("[Enter]", key_style),
-            Span::raw(" Run selected    "),
-            Span::styled("[k]", key_style),
-            Span::raw(" Move up"),
-        ]),
-        Line::from(vec![
-            Span::styled("[j]", key_style),
-            Span::raw(" Move down    "),
-            Span::styled("[Tab]", key_style),
-            Span::raw(" Next panel    "),
-            Span::styled("[Shift+Tab]", key_style),
-            Span::raw(" Previous panel    "),
-            Span::styled("[q]", key_style),
-            Span::raw(" Quit"),
-        ]),
-        Line::from(vec![
-            Span::styled("Status:", Style::default().fg(TEXT_MUTED)),
-            Span::raw(" "),
-            Span::styled(app.status(), Style::default().fg(TEXT_PRIMARY)),
-        ]),
-    ];
+    let nav_lines = if app.is_palette_active() {
+        vec![
+            Line::from(vec![
+                Span::styled("[Enter]", key_style),
+                Span::raw(" Go to / launch    "),
+                Span::styled("[Esc]", key_style),
+                Span::raw(" Cancel"),
+            ]),
+            Line::from(Span::raw("Type a module or action id.")),
+        ]
+    } else if app.focus() == Focus::Output {
+        vec![
+            Line::from(vec![
+                Span::styled("[j/k]", key_style),
+                Span::raw(" Scroll output    "),
+                Span::styled("[c]", key_style),
+                Span::raw(" Cancel running job    "),
+                Span::styled("[Esc]", key_style),
+                Span::raw(" Back"),
+            ]),
+            Line::from(vec![
+                Span::styled("[Ctrl+C]", key_style),
+                Span::raw(" Exit toolbox    "),
+                Span::styled("[q]", key_style),
+                Span::raw(" Quit"),
+            ]),
+        ]
+    } else if app.focus() == Focus::Params {
+        vec![
+            Line::from(vec![
+                Span::styled("[Tab]", key_style),
+                Span::raw(" Next field    "),
+                Span::styled("[Shift+Tab]", key_style),
+                Span::raw(" Previous field    "),
+                Span::styled("[Enter]", key_style),
+                Span::raw(" Run"),
+            ]),
+            Line::from(vec![Span::styled("[Esc]", key_style), Span::raw(" Cancel")]),
+        ]
+    } else {
+        vec![
+            Line::from(vec![
+                Span::styled("[g]", key_style),
+                Span::raw(" Show tabs    "),
+                Span::styled("[Ctrl+C]", key_style),
+                Span::raw(" Exit toolbox    "),
+                Span::styled("[Enter]", key_style),
+                Span::raw(" Run selected    "),
+                Span::styled("[k]", key_style),
+                Span::raw(" Move up"),
+            ]),
+            Line::from(vec![
+                Span::styled("[j]", key_style),
+                Span::raw(" Move down    "),
+                Span::styled("[Tab]", key_style),
+                Span::raw(" Next panel    "),
+                Span::styled("[Shift+Tab]", key_style),
+                Span::raw(" Previous panel    "),
+                Span::styled("[:]", key_style),
+                Span::raw(" Go to module/action    "),
+                Span::styled("[q]", key_style),
+                Span::raw(" Quit"),
+            ]),
+        ]
+    };
+
+    let mut lines = nav_lines;
+    lines.push(Line::from(vec![
+        Span::styled("Status:", Style::default().fg(TEXT_MUTED)),
+        Span::raw(" "),
+        Span::styled(app.status(), Style::default().fg(TEXT_PRIMARY)),
+    ]));
 
     let footer = Paragraph::new(lines).wrap(Wrap { trim: true }).block(footer_block);
 
     f.render_widget(footer, area);
 }
 
+/// Render a scrollable overlay with the captured output of the running or
+/// just-finished action, replacing the three-panel body while active.
+fn render_output(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Clear, area);
+
+    let title = if app.is_running() { "Output (running…)" } else { "Output" };
+
+    let block = Block::default()
+        .title(Span::styled(title, Style::default().fg(ACCENT).add_modifier(Modifier::BOLD | Modifier::ITALIC)))
+        .borders(Borders::ALL)
+        .border_style(border_style(true))
+        .style(Style::default().bg(PANEL_BG).fg(TEXT_PRIMARY));
+
+    let output = app.output();
+    let lines: Vec<Line> = if output.is_empty() {
+        vec![Line::from(Span::styled("(no output yet)", Style::default().fg(TEXT_MUTED)))]
+    } else {
+        output
+            .iter()
+            .skip(app.output_scroll())
+            .map(|line| {
+                let style = if line.starts_with("! ") {
+                    Style::default().fg(Color::Rgb(220, 120, 110))
+                } else if line.starts_with('✓') {
+                    Style::default().fg(SUCCESS)
+                } else if line.starts_with("== step") {
+                    Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(TEXT_PRIMARY)
+                };
+                Line::from(Span::styled(line.clone(), style))
+            })
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, area);
+}
+
+/// Render a form of the selected action's declared parameters, masking any marked `secret`.
+fn render_param_form(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            "Parameters",
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD | Modifier::ITALIC),
+        ))
+        .borders(Borders::ALL)
+        .border_style(border_style(true))
+        .style(Style::default().bg(PANEL_BG).fg(TEXT_PRIMARY));
+
+    let Some((params, values, field_index)) = app.pending_params() else {
+        f.render_widget(block, area);
+        return;
+    };
+
+    let lines: Vec<Line> = params
+        .iter()
+        .zip(values.iter())
+        .enumerate()
+        .map(|(index, (param, value))| {
+            let focused = index == field_index;
+            let label_style = if focused {
+                Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(TEXT_MUTED)
+            };
+            let value_style = if focused {
+                Style::default().fg(Color::Black).bg(ACCENT)
+            } else {
+                Style::default().fg(TEXT_PRIMARY)
+            };
+
+            let displayed = if param.secret { "*".repeat(value.chars().count()) } else { value.clone() };
+            let cursor = if focused { "█" } else { "" };
+
+            Line::from(vec![
+                Span::styled(format!("{}: ", param.name), label_style),
+                Span::styled(format!("{displayed}{cursor}"), value_style),
+            ])
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines).block(block);
+    f.render_widget(paragraph, area);
+}
+
+/// Render the command-palette overlay: a single line prompting for a module or
+/// action id to jump to or launch directly.
+fn render_palette(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(Span::styled(
+            "Go to module/action",
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD | Modifier::ITALIC),
+        ))
+        .borders(Borders::ALL)
+        .border_style(border_style(true))
+        .style(Style::default().bg(PANEL_BG).fg(TEXT_PRIMARY));
+
+    let line = Line::from(vec![
+        Span::styled(": ", Style::default().fg(ACCENT)),
+        Span::styled(format!("{}█", app.palette()), Style::default().fg(TEXT_PRIMARY)),
+    ]);
+
+    let paragraph = Paragraph::new(line).block(block);
+    f.render_widget(paragraph, area);
+}
+
 fn border_style(focused: bool) -> Style {
     if focused {
         Style::default().fg(ACCENT)
@@ -359,19 +556,65 @@ fn border_style(focused: bool) -> Style {
 }
 
 fn short_tag(name: &str) -> String {
+    const TAG_WIDTH: usize = 3;
+
     let mut tag = String::new();
+    let mut width = 0;
+
     for part in name.split_whitespace() {
-        if let Some(ch) = part.chars().next() {
-            tag.push(ch.to_ascii_uppercase());
+        let Some(first) = part.graphemes(true).next() else { continue };
+        let upper = first.to_uppercase();
+        let upper_width = upper.width();
+
+        if width + upper_width > TAG_WIDTH {
+            break;
         }
-        if tag.len() >= 3 {
+
+        tag.push_str(&upper);
+        width += upper_width;
+
+        if width >= TAG_WIDTH {
             break;
         }
     }
 
-    while tag.len() < 3 {
-        tag.push(' ');
+    pad_to_width(&tag, TAG_WIDTH)
+}
+
+/// Truncate `text` to at most `max_width` display columns, breaking on
+/// grapheme-cluster boundaries and appending an ellipsis if anything was cut.
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_string();
+    }
+
+    if max_width == 0 {
+        return String::new();
     }
 
-    tag
+    let budget = max_width.saturating_sub(1);
+    let mut result = String::new();
+    let mut width = 0;
+
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if width + grapheme_width > budget {
+            break;
+        }
+        result.push_str(grapheme);
+        width += grapheme_width;
+    }
+
+    result.push('…');
+    result
+}
+
+/// Pad `text` with spaces until it occupies `target_width` display columns.
+fn pad_to_width(text: &str, target_width: usize) -> String {
+    let mut result = text.to_string();
+    let width = result.width();
+    if width < target_width {
+        result.push_str(&" ".repeat(target_width - width));
+    }
+    result
 }